@@ -1,20 +1,46 @@
 use std::fmt;
 
+/// The single error type surfaced across the crate's `error`, `relative`,
+/// `parse` and `span` modules.
+///
+/// Following chrono's own move away from `Option`/panic toward explicit
+/// `Err(Error)` constructors, every fallible entry point returns this enum.
 #[derive(Debug, PartialEq)]
-pub enum TempusError {
+pub enum PeriodError {
+    /// The input did not match any known relative-time grammar.
+    ParseError { input: String },
+    /// Month or year arithmetic could not produce a valid calendar date
+    /// (for example, stepping off the representable `NaiveDate` range).
+    AmbiguousDate { input: String },
+    /// A `Duration` or `Months` addition would exceed the representable
+    /// `NaiveDate`/`DateTime` range.
+    OutOfRange { unit: String },
+    /// A relative constructor received a negative count where a non-negative
+    /// one was expected; `suggestion` names the opposite-direction helper.
     NegativeValue { unit: String, suggestion: String, value: i64 },
 }
 
-impl fmt::Display for TempusError {
+impl fmt::Display for PeriodError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            TempusError::NegativeValue { unit, suggestion, value } => {
+            PeriodError::ParseError { input } => {
+                write!(f, "could not parse relative-time expression: {input:?}")
+            }
+            PeriodError::AmbiguousDate { input } => {
+                write!(f, "ambiguous calendar arithmetic for: {input:?}")
+            }
+            PeriodError::OutOfRange { unit } => {
+                write!(f, "{unit} arithmetic is out of the representable range")
+            }
+            PeriodError::NegativeValue { unit, suggestion, value } => {
                 write!(
                     f,
-                    "{} must be positive. Did you mean {}({})?",
-                    unit, suggestion, value
+                    "{unit} must be positive. Did you mean {suggestion}({})?",
+                    value.abs()
                 )
             }
         }
     }
 }
+
+impl std::error::Error for PeriodError {}
@@ -1,4 +1,6 @@
-use chrono::{DateTime, Local, NaiveDate};
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+
+use crate::locale::{self, Locale, LongDateStyle};
 
 /// Converts a NaiveDate to a string in "YYYY-MM-DD" format.
 #[must_use]
@@ -9,18 +11,35 @@ pub fn to_date_string(date: NaiveDate) -> String {
 
 /// Converts a NaiveDate to a string in "Month Day, Year" format (e.g., "February 22, 2026").
 /// This uses the full month name.
-/// Note: This will be in English regardless of locale.
-/// For locale-aware formatting, consider using the `chrono_locale` crate.
+/// This is a thin wrapper around [`to_long_date_localized`] defaulting to the
+/// built-in English locale; its output is unchanged (space-padded day, English
+/// month name).
 /// Example: `to_long_date(NaiveDate::from_ymd_opt(2026, 2, 22).unwrap())` returns "February 22, 2026".
-/// This is a simple wrapper around `chrono`'s formatting capabilities.
-/// The format string "%B %e, %Y" means:
-/// - %B: Full month name (e.g., "February")
-/// - %e: Day of the month, space-padded (e.g., "22")
-/// - %Y: Year with century (e.g., "2026")
 #[must_use]
 #[inline]
 pub fn to_long_date(date: NaiveDate) -> String {
-    date.format("%B %e, %Y").to_string()
+    to_long_date_localized(date, &Locale::English)
+}
+
+/// Converts a NaiveDate to a long-form string rendered in `locale`.
+///
+/// The full month name and the ordering of day/month/year are pulled from the
+/// locale's string table, mirroring how chrono's `format/locales` module swaps
+/// month names. English renders `"February 22, 2026"`; Spanish renders
+/// `"22 de febrero de 2026"`.
+#[must_use]
+pub fn to_long_date_localized(date: NaiveDate, locale: &Locale) -> String {
+    let strings = locale::resolve(locale);
+    let month = &strings.months[(date.month0()) as usize];
+    let day = date.day();
+    let year = date.year();
+    match &strings.long_date {
+        LongDateStyle::MonthDayYear => format!("{month} {day:>2}, {year}"),
+        LongDateStyle::DayMonthYear {
+            before_month,
+            before_year,
+        } => format!("{day}{before_month}{month}{before_year}{year}"),
+    }
 }
 
 /// Converts a DateTime<Local> to an ISO 8601 string in the format "YYYY-MM-DDTHH:MM:SS+00:00".
@@ -60,6 +79,15 @@ mod tests {
         assert_eq!(to_long_date(date), "February 22, 2026");
     }
 
+    #[test]
+    fn test_to_long_date_localized_spanish() {
+        let date = NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        assert_eq!(
+            to_long_date_localized(date, &crate::locale::Locale::Spanish),
+            "22 de febrero de 2026"
+        );
+    }
+
     #[test]
     fn test_to_long_date_with_single_digit_day() {
         let date = NaiveDate::from_ymd_opt(2026, 2, 5).unwrap();
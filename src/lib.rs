@@ -1,13 +1,26 @@
 mod error;
+mod formatting;
+mod locale;
 mod now;
+mod parse;
+mod recur;
 mod relative;
+mod span;
 
 pub use error::PeriodError;
+pub use formatting::{
+    to_date_string, to_iso8601, to_long_date, to_long_date_localized, to_rfc2822,
+};
+pub use locale::{register_locale, Locale, LocaleStrings, LongDateStyle};
 pub use now::{now, today};
+pub use parse::{parse, parse_datetime};
+pub use recur::{RecurStep, Recurrence};
 pub use relative::{
     days_ago, days_ago_datetime, days_from_now, days_from_now_datetime, hours_ago, hours_from_now,
-    humanize, minutes_ago, minutes_from_now, months_ago, months_ago_datetime, months_from_now,
-    months_from_now_datetime, seconds_ago, seconds_from_now, tomorrow, weeks_ago,
-    weeks_ago_datetime, weeks_from_now, weeks_from_now_datetime, years_ago, years_ago_datetime,
-    years_from_now, years_from_now_datetime, yesterday,
+    humanize, humanize_localized, humanize_with, minutes_ago, minutes_from_now, months_ago,
+    months_ago_datetime, months_from_now, months_from_now_datetime, seconds_ago, seconds_from_now,
+    tomorrow, weeks_ago, weeks_ago_datetime, weeks_from_now, weeks_from_now_datetime, years_ago,
+    years_ago_datetime, years_from_now, years_from_now_datetime, yesterday, HumanizeConfig,
+    HumanizeUnit, Thresholds,
 };
+pub use span::{span, Span};
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+/// How a locale orders the day, month and year of a long-form date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LongDateStyle {
+    /// `"February 22, 2026"` — month name, space-padded day, year.
+    MonthDayYear,
+    /// Day, month name, year, with the connectors carried by the locale rather
+    /// than imposed by the ordering. Spanish uses `" de "` for both
+    /// (`"22 de febrero de 2026"`); French uses `" "`/`" "`
+    /// (`"22 février 2026"`).
+    DayMonthYear {
+        /// Connector rendered between the day and the month name.
+        before_month: String,
+        /// Connector rendered between the month name and the year.
+        before_year: String,
+    },
+}
+
+/// The per-locale string tables used to render `humanize` and `to_long_date`.
+///
+/// The bucketing thresholds in `humanize_impl` stay identical across locales;
+/// only these rendered tokens change. Construct one by hand and hand it to
+/// [`register_locale`] to add a locale beyond the bundled ones, mirroring how
+/// chrono's `format/locales` module swaps month and weekday names.
+#[derive(Debug, Clone)]
+pub struct LocaleStrings {
+    pub just_now: String,
+    pub yesterday: String,
+    pub tomorrow: String,
+    /// Template wrapping a past core, with `{}` where the core goes
+    /// (English `"{} ago"`).
+    pub past: String,
+    /// Template wrapping a future core (English `"in {}"`).
+    pub future: String,
+    /// Article form for the `< 90 s` bucket (English `"a minute"`).
+    pub a_minute: String,
+    /// Article form for the `< 90 min` bucket (English `"an hour"`).
+    pub an_hour: String,
+    /// Article form for the `< 45 days` bucket (English `"a month"`).
+    pub a_month: String,
+    /// Article form for the `< 18 months` bucket (English `"a year"`).
+    pub a_year: String,
+    pub minute: (String, String),
+    pub hour: (String, String),
+    pub day: (String, String),
+    pub month: (String, String),
+    pub year: (String, String),
+    /// Full month names, January first, for long-form dates.
+    pub months: [String; 12],
+    pub long_date: LongDateStyle,
+}
+
+impl LocaleStrings {
+    /// Selects the singular or plural form of a `(singular, plural)` pair.
+    #[must_use]
+    pub(crate) fn plural<'a>(n: i64, forms: &'a (String, String)) -> &'a str {
+        if n == 1 {
+            &forms.0
+        } else {
+            &forms.1
+        }
+    }
+}
+
+/// A locale: one of the bundled languages, or a custom one registered by tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Locale {
+    English,
+    Spanish,
+    /// A locale registered under a BCP-47 tag via [`register_locale`].
+    Custom(String),
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::English
+    }
+}
+
+fn registry() -> &'static RwLock<HashMap<String, LocaleStrings>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, LocaleStrings>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Registers a custom locale under a BCP-47 `tag`, retrievable as
+/// [`Locale::Custom`].
+pub fn register_locale(tag: impl Into<String>, strings: LocaleStrings) {
+    registry()
+        .write()
+        .expect("locale registry lock poisoned")
+        .insert(tag.into(), strings);
+}
+
+/// Resolves a [`Locale`] to its string table, falling back to English for an
+/// unregistered custom tag.
+pub(crate) fn resolve(locale: &Locale) -> LocaleStrings {
+    match locale {
+        Locale::English => english(),
+        Locale::Spanish => spanish(),
+        Locale::Custom(tag) => registry()
+            .read()
+            .expect("locale registry lock poisoned")
+            .get(tag)
+            .cloned()
+            .unwrap_or_else(english),
+    }
+}
+
+fn pair(singular: &str, plural: &str) -> (String, String) {
+    (singular.to_string(), plural.to_string())
+}
+
+fn month_names(names: [&str; 12]) -> [String; 12] {
+    names.map(String::from)
+}
+
+pub(crate) fn english() -> LocaleStrings {
+    LocaleStrings {
+        just_now: "just now".to_string(),
+        yesterday: "yesterday".to_string(),
+        tomorrow: "tomorrow".to_string(),
+        past: "{} ago".to_string(),
+        future: "in {}".to_string(),
+        a_minute: "a minute".to_string(),
+        an_hour: "an hour".to_string(),
+        a_month: "a month".to_string(),
+        a_year: "a year".to_string(),
+        minute: pair("minute", "minutes"),
+        hour: pair("hour", "hours"),
+        day: pair("day", "days"),
+        month: pair("month", "months"),
+        year: pair("year", "years"),
+        months: month_names([
+            "January", "February", "March", "April", "May", "June", "July", "August",
+            "September", "October", "November", "December",
+        ]),
+        long_date: LongDateStyle::MonthDayYear,
+    }
+}
+
+pub(crate) fn spanish() -> LocaleStrings {
+    LocaleStrings {
+        just_now: "justo ahora".to_string(),
+        yesterday: "ayer".to_string(),
+        tomorrow: "mañana".to_string(),
+        past: "hace {}".to_string(),
+        future: "dentro de {}".to_string(),
+        a_minute: "un minuto".to_string(),
+        an_hour: "una hora".to_string(),
+        a_month: "un mes".to_string(),
+        a_year: "un año".to_string(),
+        minute: pair("minuto", "minutos"),
+        hour: pair("hora", "horas"),
+        day: pair("día", "días"),
+        month: pair("mes", "meses"),
+        year: pair("año", "años"),
+        months: month_names([
+            "enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto",
+            "septiembre", "octubre", "noviembre", "diciembre",
+        ]),
+        long_date: LongDateStyle::DayMonthYear {
+            before_month: " de ".to_string(),
+            before_year: " de ".to_string(),
+        },
+    }
+}
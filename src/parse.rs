@@ -0,0 +1,266 @@
+use chrono::{DateTime, Duration, Local, Months, NaiveDate};
+
+use crate::error::PeriodError;
+use crate::relative::{days_ago, days_from_now, weeks_ago, weeks_from_now};
+
+/// Parses a natural-language relative-time phrase into a concrete date.
+///
+/// This is the inverse of [`humanize`](crate::humanize): where `humanize`
+/// renders a `DateTime` as `"3 days ago"`, `parse` turns phrases like
+/// `"3 days ago"`, `"in 2 weeks"`, `"yesterday"`, `"tomorrow"`, `"next month"`
+/// or `"5 hours from now"` back into a `NaiveDate` relative to `Local::now()`.
+///
+/// The grammar is modeled on the kairos/two-timer style:
+///
+/// - an optional leading integer amount (defaulting to `1`, and accepting the
+///   articles `"a"`/`"an"`),
+/// - a unit word (`second[s]`/`sec`/`s`, `minute[s]`/`min`, `hour[s]`/`hr`,
+///   `day[s]`/`d`, `week[s]`/`w`, `month[s]`, `year[s]`/`yr`),
+/// - a direction token (`ago`, `from now`, `in`, `last`, `next`),
+///
+/// plus the bare keywords `today`, `yesterday`, `tomorrow` and `now`.
+///
+/// Day/week (and sub-day) deltas are applied through [`chrono::Duration`];
+/// month and year deltas are applied through [`chrono::Months`] so that
+/// `"1 month ago"` lands on a real calendar month rather than a fixed 30 days.
+///
+/// # Errors
+///
+/// Returns [`PeriodError::ParseError`] when the input matches no known grammar,
+/// and [`PeriodError::AmbiguousDate`] when month/year arithmetic would step off
+/// the representable `NaiveDate` range.
+pub fn parse(input: &str) -> Result<NaiveDate, PeriodError> {
+    let (amount, unit) = parse_spec(input)?;
+    apply_date(input, amount, unit)
+}
+
+/// Like [`parse`], but resolves to a `DateTime<Local>` so that sub-day phrases
+/// such as `"5 hours from now"` retain their time-of-day component.
+///
+/// # Errors
+///
+/// Mirrors [`parse`]: [`PeriodError::ParseError`] for unrecognised input and
+/// [`PeriodError::AmbiguousDate`] for out-of-range month/year arithmetic.
+pub fn parse_datetime(input: &str) -> Result<DateTime<Local>, PeriodError> {
+    let (amount, unit) = parse_spec(input)?;
+    apply_datetime(input, amount, unit)
+}
+
+/// The temporal units understood by the parser.
+#[derive(Clone, Copy)]
+enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Unit {
+    fn from_word(word: &str) -> Option<Self> {
+        match word {
+            "second" | "seconds" | "sec" | "s" => Some(Unit::Second),
+            "minute" | "minutes" | "min" => Some(Unit::Minute),
+            "hour" | "hours" | "hr" => Some(Unit::Hour),
+            "day" | "days" | "d" => Some(Unit::Day),
+            "week" | "weeks" | "w" => Some(Unit::Week),
+            "month" | "months" => Some(Unit::Month),
+            "year" | "years" | "yr" => Some(Unit::Year),
+            _ => None,
+        }
+    }
+}
+
+/// Reduces an input phrase to a signed amount (positive = future) and a unit.
+fn parse_spec(input: &str) -> Result<(i64, Unit), PeriodError> {
+    let lower = input.trim().to_lowercase();
+    let tokens: Vec<&str> = lower.split_whitespace().collect();
+
+    let parse_err = || PeriodError::ParseError { input: input.to_string() };
+
+    match tokens.as_slice() {
+        ["today"] => Ok((0, Unit::Day)),
+        ["now"] => Ok((0, Unit::Second)),
+        ["yesterday"] => Ok((-1, Unit::Day)),
+        ["tomorrow"] => Ok((1, Unit::Day)),
+        ["next", unit] => Ok((1, Unit::from_word(unit).ok_or_else(parse_err)?)),
+        ["last", unit] => Ok((-1, Unit::from_word(unit).ok_or_else(parse_err)?)),
+        ["in", rest @ ..] => {
+            let (amount, unit) = amount_unit(rest, input)?;
+            Ok((amount, unit))
+        }
+        [rest @ .., "from", "now"] => {
+            let (amount, unit) = amount_unit(rest, input)?;
+            Ok((amount, unit))
+        }
+        [rest @ .., "ago"] => {
+            let (amount, unit) = amount_unit(rest, input)?;
+            Ok((-amount, unit))
+        }
+        _ => Err(parse_err()),
+    }
+}
+
+/// Parses a `[amount] unit` slice, defaulting a missing amount to `1`.
+fn amount_unit(tokens: &[&str], input: &str) -> Result<(i64, Unit), PeriodError> {
+    let parse_err = || PeriodError::ParseError { input: input.to_string() };
+    match tokens {
+        [unit] => Ok((1, Unit::from_word(unit).ok_or_else(parse_err)?)),
+        [amount, unit] => {
+            let n = if matches!(*amount, "a" | "an") {
+                1
+            } else {
+                amount.parse::<i64>().map_err(|_| parse_err())?
+            };
+            Ok((n, Unit::from_word(unit).ok_or_else(parse_err)?))
+        }
+        _ => Err(parse_err()),
+    }
+}
+
+/// Applies a signed amount of `unit` to today's date.
+fn apply_date(input: &str, amount: i64, unit: Unit) -> Result<NaiveDate, PeriodError> {
+    match unit {
+        Unit::Second | Unit::Minute | Unit::Hour => {
+            Ok((Local::now() + duration(unit, amount)).date_naive())
+        }
+        Unit::Day => signed(amount, days_from_now, days_ago),
+        Unit::Week => signed(amount, weeks_from_now, weeks_ago),
+        Unit::Month => add_months(input, Local::now().date_naive(), amount),
+        Unit::Year => add_months(input, Local::now().date_naive(), amount * 12),
+    }
+}
+
+/// Applies a signed amount of `unit` to the current instant.
+fn apply_datetime(input: &str, amount: i64, unit: Unit) -> Result<DateTime<Local>, PeriodError> {
+    let now = Local::now();
+    match unit {
+        Unit::Second | Unit::Minute | Unit::Hour | Unit::Day | Unit::Week => {
+            Ok(now + duration(unit, amount))
+        }
+        Unit::Month => add_months_dt(input, now, amount),
+        Unit::Year => add_months_dt(input, now, amount * 12),
+    }
+}
+
+/// Dispatches to a future- or past-facing relative helper based on sign.
+fn signed(
+    amount: i64,
+    future: fn(i64) -> Result<NaiveDate, PeriodError>,
+    past: fn(i64) -> Result<NaiveDate, PeriodError>,
+) -> Result<NaiveDate, PeriodError> {
+    if amount >= 0 {
+        future(amount)
+    } else {
+        past(-amount)
+    }
+}
+
+fn duration(unit: Unit, amount: i64) -> Duration {
+    match unit {
+        Unit::Second => Duration::seconds(amount),
+        Unit::Minute => Duration::minutes(amount),
+        Unit::Hour => Duration::hours(amount),
+        Unit::Day => Duration::days(amount),
+        Unit::Week => Duration::weeks(amount),
+        // Month/year are never routed through `Duration`.
+        Unit::Month | Unit::Year => Duration::zero(),
+    }
+}
+
+fn add_months(input: &str, date: NaiveDate, amount: i64) -> Result<NaiveDate, PeriodError> {
+    let months = months_of(input, amount)?;
+    let result = if amount >= 0 {
+        date.checked_add_months(months)
+    } else {
+        date.checked_sub_months(months)
+    };
+    result.ok_or_else(|| PeriodError::AmbiguousDate { input: input.to_string() })
+}
+
+fn add_months_dt(
+    input: &str,
+    datetime: DateTime<Local>,
+    amount: i64,
+) -> Result<DateTime<Local>, PeriodError> {
+    let months = months_of(input, amount)?;
+    let result = if amount >= 0 {
+        datetime.checked_add_months(months)
+    } else {
+        datetime.checked_sub_months(months)
+    };
+    result.ok_or_else(|| PeriodError::AmbiguousDate { input: input.to_string() })
+}
+
+fn months_of(input: &str, amount: i64) -> Result<Months, PeriodError> {
+    let n = u32::try_from(amount.unsigned_abs())
+        .map_err(|_| PeriodError::AmbiguousDate { input: input.to_string() })?;
+    Ok(Months::new(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relative::{months_ago, months_from_now, yesterday};
+
+    #[test]
+    fn test_parse_today() {
+        assert_eq!(parse("today").unwrap(), Local::now().date_naive());
+    }
+
+    #[test]
+    fn test_parse_yesterday() {
+        assert_eq!(parse("yesterday").unwrap(), yesterday().unwrap());
+    }
+
+    #[test]
+    fn test_parse_tomorrow() {
+        assert_eq!(parse("tomorrow").unwrap(), days_from_now(1).unwrap());
+    }
+
+    #[test]
+    fn test_parse_days_ago() {
+        assert_eq!(parse("3 days ago").unwrap(), days_ago(3).unwrap());
+    }
+
+    #[test]
+    fn test_parse_in_weeks() {
+        assert_eq!(parse("in 2 weeks").unwrap(), weeks_from_now(2).unwrap());
+    }
+
+    #[test]
+    fn test_parse_days_from_now() {
+        assert_eq!(parse("5 days from now").unwrap(), days_from_now(5).unwrap());
+    }
+
+    #[test]
+    fn test_parse_next_month() {
+        assert_eq!(parse("next month").unwrap(), months_from_now(1).unwrap());
+    }
+
+    #[test]
+    fn test_parse_last_month() {
+        assert_eq!(parse("last month").unwrap(), months_ago(1).unwrap());
+    }
+
+    #[test]
+    fn test_parse_article_amount() {
+        assert_eq!(parse("a week ago").unwrap(), weeks_ago(1).unwrap());
+    }
+
+    #[test]
+    fn test_parse_unknown_is_error() {
+        assert_eq!(
+            parse("whenever"),
+            Err(PeriodError::ParseError { input: "whenever".to_string() })
+        );
+    }
+
+    #[test]
+    fn test_parse_datetime_hours_from_now_is_future() {
+        let result = parse_datetime("5 hours from now").unwrap();
+        assert!(result > Local::now());
+    }
+}
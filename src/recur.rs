@@ -0,0 +1,153 @@
+use chrono::{Duration, Months, NaiveDate};
+
+/// The stride between successive dates produced by a [`Recurrence`].
+///
+/// Day and week strides are applied through [`chrono::Duration`]; month and
+/// year strides through [`chrono::Months`], so that adding a month to
+/// `Jan 31` clamps to the last valid day of the target month (`Feb 28`/`29`)
+/// rather than overflowing into March.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurStep {
+    Days(i64),
+    Weeks(i64),
+    Months(u32),
+    Years(u32),
+}
+
+/// A lazy iterator over a recurring series of dates, modeled on kairos's
+/// `every … until … / times` grammar.
+///
+/// Build one with [`Recurrence::new`] and an optional terminator
+/// ([`Recurrence::until`] or [`Recurrence::times`]); with no terminator the
+/// iterator is infinite. Each [`Iterator::next`] yields the current cursor and
+/// advances it by the configured [`RecurStep`].
+///
+/// ```ignore
+/// let start = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+/// let dates: Vec<_> = Recurrence::new(start, RecurStep::Months(1)).times(3).collect();
+/// // 2026-01-31, 2026-02-28, 2026-03-28
+/// ```
+#[derive(Debug, Clone)]
+pub struct Recurrence {
+    next: Option<NaiveDate>,
+    step: RecurStep,
+    until: Option<NaiveDate>,
+    remaining: Option<usize>,
+}
+
+impl Recurrence {
+    /// Creates a recurrence starting at `start` that advances by `step`.
+    ///
+    /// Without a terminator the iterator is lazy and infinite.
+    #[must_use]
+    pub fn new(start: NaiveDate, step: RecurStep) -> Self {
+        Recurrence { next: Some(start), step, until: None, remaining: None }
+    }
+
+    /// Stops the iterator once the cursor passes `date` (inclusive).
+    #[must_use]
+    pub fn until(mut self, date: NaiveDate) -> Self {
+        self.until = Some(date);
+        self
+    }
+
+    /// Stops the iterator after `n` dates have been yielded.
+    #[must_use]
+    pub fn times(mut self, n: usize) -> Self {
+        self.remaining = Some(n);
+        self
+    }
+}
+
+impl Iterator for Recurrence {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        let current = self.next?;
+
+        if self.remaining == Some(0) {
+            return None;
+        }
+        if let Some(until) = self.until {
+            if current > until {
+                return None;
+            }
+        }
+
+        self.next = advance(current, self.step);
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        Some(current)
+    }
+}
+
+/// Advances `date` by one `step`, returning `None` if the result would fall
+/// outside the representable `NaiveDate` range.
+fn advance(date: NaiveDate, step: RecurStep) -> Option<NaiveDate> {
+    match step {
+        RecurStep::Days(n) => date.checked_add_signed(Duration::days(n)),
+        RecurStep::Weeks(n) => date.checked_add_signed(Duration::weeks(n)),
+        RecurStep::Months(n) => date.checked_add_months(Months::new(n)),
+        RecurStep::Years(n) => date.checked_add_months(Months::new(n.saturating_mul(12))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_recur_times_days() {
+        let dates: Vec<_> = Recurrence::new(ymd(2026, 1, 1), RecurStep::Days(2)).times(3).collect();
+        assert_eq!(dates, vec![ymd(2026, 1, 1), ymd(2026, 1, 3), ymd(2026, 1, 5)]);
+    }
+
+    #[test]
+    fn test_recur_times_weeks() {
+        let dates: Vec<_> =
+            Recurrence::new(ymd(2026, 1, 1), RecurStep::Weeks(2)).times(2).collect();
+        assert_eq!(dates, vec![ymd(2026, 1, 1), ymd(2026, 1, 15)]);
+    }
+
+    #[test]
+    fn test_recur_until_is_inclusive() {
+        let dates: Vec<_> = Recurrence::new(ymd(2026, 1, 1), RecurStep::Days(1))
+            .until(ymd(2026, 1, 3))
+            .collect();
+        assert_eq!(dates, vec![ymd(2026, 1, 1), ymd(2026, 1, 2), ymd(2026, 1, 3)]);
+    }
+
+    #[test]
+    fn test_recur_month_end_clamps() {
+        let dates: Vec<_> =
+            Recurrence::new(ymd(2026, 1, 31), RecurStep::Months(1)).times(3).collect();
+        assert_eq!(dates, vec![ymd(2026, 1, 31), ymd(2026, 2, 28), ymd(2026, 3, 28)]);
+    }
+
+    #[test]
+    fn test_recur_years() {
+        let dates: Vec<_> =
+            Recurrence::new(ymd(2024, 2, 29), RecurStep::Years(1)).times(2).collect();
+        assert_eq!(dates, vec![ymd(2024, 2, 29), ymd(2025, 2, 28)]);
+    }
+
+    #[test]
+    fn test_recur_times_zero_yields_nothing() {
+        let dates: Vec<_> =
+            Recurrence::new(ymd(2026, 1, 1), RecurStep::Days(1)).times(0).collect();
+        assert!(dates.is_empty());
+    }
+
+    #[test]
+    fn test_recur_is_lazy_and_infinite() {
+        let dates: Vec<_> =
+            Recurrence::new(ymd(2026, 1, 1), RecurStep::Days(1)).take(5).collect();
+        assert_eq!(dates.len(), 5);
+        assert_eq!(dates[4], ymd(2026, 1, 5));
+    }
+}
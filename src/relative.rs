@@ -1,102 +1,13 @@
-use chrono::{Duration, Local, Months, NaiveDate};
-
-pub fn days_ago(days: i64) -> NaiveDate {
-    Local::now().date_naive() - Duration::days(days)
-}
-
-pub fn days_from_now(days: i64) -> NaiveDate {
-    Local::now().date_naive() + Duration::days(days)
-}
-
-pub fn weeks_ago(weeks: i64) -> NaiveDate {
-    Local::now().date_naive() - Duration::weeks(weeks)
-}
-
-pub fn weeks_from_now(weeks: i64) -> NaiveDate {
-    Local::now().date_naive() + Duration::weeks(weeks)
-}
-
-pub fn yesterday() -> NaiveDate {
-    days_ago(1)
-}
-
-pub fn tomorrow() -> NaiveDate {
-    days_from_now(1)
-}
-
-pub fn months_ago(months: u32) -> NaiveDate {
-    Local::now().date_naive() - Months::new(months)
-}
-
-pub fn months_from_now(months: u32) -> NaiveDate {
-    Local::now().date_naive() + Months::new(months)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use chrono::Local;
-
-    #[test]
-    fn test_days_ago_returns_correct_date() {
-        let date = days_ago(3);
-        let expected = Local::now().date_naive() - Duration::days(3);
-        assert_eq!(date, expected);
-    }
-
-    #[test]
-    fn test_days_ago_with_zero_days() {
-        let date = days_ago(0);
-        let expected = Local::now().date_naive();
-        assert_eq!(date, expected);
-    }
-
-    #[test]
-    fn test_days_from_now_returns_correct_date() {
-        let date = days_from_now(3);
-        let expected = Local::now().date_naive() + Duration::days(3);
-        assert_eq!(date, expected);
-    }
-
-    #[test]
-    fn test_weeks_ago_returns_correct_date() {
-        let date = weeks_ago(2);
-        let expected = Local::now().date_naive() - Duration::weeks(2);
-        assert_eq!(date, expected);
-    }
-
-    #[test]
-    fn test_weeks_from_now_returns_correct_date() {
-        let date = weeks_from_now(2);
-        let expected = Local::now().date_naive() + Duration::weeks(2);
-        assert_eq!(date, expected);
-    }
-
-    #[test]
-    fn test_yesterday_returns_previous_date() {
-        let date = yesterday();
-        let expected = Local::now().date_naive() - Duration::days(1);
-        assert_eq!(date, expected);
-    }
-
-    #[test]
-    fn test_tomorrow_returns_next_date() {
-        let date = tomorrow();
-        let expected = Local::now().date_naive() + Duration::days(1);
-        assert_eq!(date, expected);
-    }
-
-    #[test]
-    fn test_months_ago_returns_correct_date() {
-        let date = months_ago(2);
-        let expected = Local::now().date_naive() - Months::new(2);
-        assert_eq!(date, expected);
-    }
-
-    #[test]
-    fn test_months_from_now_returns_correct_date() {
-        let date = months_from_now(2);
-        let expected = Local::now().date_naive() + Months::new(2);
-        assert_eq!(date, expected);
-    }
-}
+mod functions;
+mod humanize;
+
+pub use functions::{
+    days_ago, days_ago_datetime, days_from_now, days_from_now_datetime, hours_ago, hours_from_now,
+    minutes_ago, minutes_from_now, months_ago, months_ago_datetime, months_from_now,
+    months_from_now_datetime, seconds_ago, seconds_from_now, tomorrow, weeks_ago,
+    weeks_ago_datetime, weeks_from_now, weeks_from_now_datetime, years_ago, years_ago_datetime,
+    years_from_now, years_from_now_datetime, yesterday,
+};
+pub use humanize::{
+    humanize, humanize_localized, humanize_with, HumanizeConfig, HumanizeUnit, Thresholds,
+};
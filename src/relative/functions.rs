@@ -0,0 +1,367 @@
+use chrono::{DateTime, Duration, Local, Months, NaiveDate};
+
+use crate::error::PeriodError;
+
+// Duration-based constructors -------------------------------------------------
+
+fn date_offset(dur: Duration, unit: &str) -> Result<NaiveDate, PeriodError> {
+    Local::now()
+        .date_naive()
+        .checked_add_signed(dur)
+        .ok_or_else(|| PeriodError::OutOfRange { unit: unit.to_string() })
+}
+
+fn datetime_offset(dur: Duration, unit: &str) -> Result<DateTime<Local>, PeriodError> {
+    Local::now()
+        .checked_add_signed(dur)
+        .ok_or_else(|| PeriodError::OutOfRange { unit: unit.to_string() })
+}
+
+/// Returns the date `days` days before today.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn days_ago(days: i64) -> Result<NaiveDate, PeriodError> {
+    date_offset(Duration::days(-days), "days")
+}
+
+/// Returns the date `days` days after today.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn days_from_now(days: i64) -> Result<NaiveDate, PeriodError> {
+    date_offset(Duration::days(days), "days")
+}
+
+/// Returns the date `weeks` weeks before today.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn weeks_ago(weeks: i64) -> Result<NaiveDate, PeriodError> {
+    date_offset(Duration::weeks(-weeks), "weeks")
+}
+
+/// Returns the date `weeks` weeks after today.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn weeks_from_now(weeks: i64) -> Result<NaiveDate, PeriodError> {
+    date_offset(Duration::weeks(weeks), "weeks")
+}
+
+/// Returns the date `hours` hours before now.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn hours_ago(hours: i64) -> Result<NaiveDate, PeriodError> {
+    date_offset(Duration::hours(-hours), "hours")
+}
+
+/// Returns the date `hours` hours after now.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn hours_from_now(hours: i64) -> Result<NaiveDate, PeriodError> {
+    date_offset(Duration::hours(hours), "hours")
+}
+
+/// Returns the date `minutes` minutes before now.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn minutes_ago(minutes: i64) -> Result<NaiveDate, PeriodError> {
+    date_offset(Duration::minutes(-minutes), "minutes")
+}
+
+/// Returns the date `minutes` minutes after now.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn minutes_from_now(minutes: i64) -> Result<NaiveDate, PeriodError> {
+    date_offset(Duration::minutes(minutes), "minutes")
+}
+
+/// Returns the date `seconds` seconds before now.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn seconds_ago(seconds: i64) -> Result<NaiveDate, PeriodError> {
+    date_offset(Duration::seconds(-seconds), "seconds")
+}
+
+/// Returns the date `seconds` seconds after now.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn seconds_from_now(seconds: i64) -> Result<NaiveDate, PeriodError> {
+    date_offset(Duration::seconds(seconds), "seconds")
+}
+
+/// Returns yesterday's date.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn yesterday() -> Result<NaiveDate, PeriodError> {
+    days_ago(1)
+}
+
+/// Returns tomorrow's date.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside `NaiveDate`'s range.
+pub fn tomorrow() -> Result<NaiveDate, PeriodError> {
+    days_from_now(1)
+}
+
+/// Returns the instant `days` days before now.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside the representable range.
+pub fn days_ago_datetime(days: i64) -> Result<DateTime<Local>, PeriodError> {
+    datetime_offset(Duration::days(-days), "days")
+}
+
+/// Returns the instant `days` days after now.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside the representable range.
+pub fn days_from_now_datetime(days: i64) -> Result<DateTime<Local>, PeriodError> {
+    datetime_offset(Duration::days(days), "days")
+}
+
+/// Returns the instant `weeks` weeks before now.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside the representable range.
+pub fn weeks_ago_datetime(weeks: i64) -> Result<DateTime<Local>, PeriodError> {
+    datetime_offset(Duration::weeks(-weeks), "weeks")
+}
+
+/// Returns the instant `weeks` weeks after now.
+///
+/// # Errors
+/// [`PeriodError::OutOfRange`] if the result falls outside the representable range.
+pub fn weeks_from_now_datetime(weeks: i64) -> Result<DateTime<Local>, PeriodError> {
+    datetime_offset(Duration::weeks(weeks), "weeks")
+}
+
+// Months-based constructors ---------------------------------------------------
+
+/// Validates a month/year count, rejecting negatives with directional guidance.
+fn count(value: i64, unit: &str, suggestion: &str) -> Result<u32, PeriodError> {
+    if value < 0 {
+        return Err(PeriodError::NegativeValue {
+            unit: unit.to_string(),
+            suggestion: suggestion.to_string(),
+            value,
+        });
+    }
+    u32::try_from(value).map_err(|_| PeriodError::OutOfRange { unit: unit.to_string() })
+}
+
+fn date_sub_months(n: u32, unit: &str) -> Result<NaiveDate, PeriodError> {
+    Local::now()
+        .date_naive()
+        .checked_sub_months(Months::new(n))
+        .ok_or_else(|| PeriodError::OutOfRange { unit: unit.to_string() })
+}
+
+fn date_add_months(n: u32, unit: &str) -> Result<NaiveDate, PeriodError> {
+    Local::now()
+        .date_naive()
+        .checked_add_months(Months::new(n))
+        .ok_or_else(|| PeriodError::OutOfRange { unit: unit.to_string() })
+}
+
+fn datetime_sub_months(n: u32, unit: &str) -> Result<DateTime<Local>, PeriodError> {
+    Local::now()
+        .checked_sub_months(Months::new(n))
+        .ok_or_else(|| PeriodError::OutOfRange { unit: unit.to_string() })
+}
+
+fn datetime_add_months(n: u32, unit: &str) -> Result<DateTime<Local>, PeriodError> {
+    Local::now()
+        .checked_add_months(Months::new(n))
+        .ok_or_else(|| PeriodError::OutOfRange { unit: unit.to_string() })
+}
+
+fn months_in_years(years: u32, unit: &str) -> Result<u32, PeriodError> {
+    years
+        .checked_mul(12)
+        .ok_or_else(|| PeriodError::OutOfRange { unit: unit.to_string() })
+}
+
+/// Returns the date `months` calendar months before today.
+///
+/// # Errors
+/// [`PeriodError::NegativeValue`] for a negative count (suggesting
+/// `months_from_now`), or [`PeriodError::OutOfRange`] on overflow.
+pub fn months_ago(months: i64) -> Result<NaiveDate, PeriodError> {
+    date_sub_months(count(months, "months", "months_from_now")?, "months")
+}
+
+/// Returns the date `months` calendar months after today.
+///
+/// # Errors
+/// [`PeriodError::NegativeValue`] for a negative count (suggesting
+/// `months_ago`), or [`PeriodError::OutOfRange`] on overflow.
+pub fn months_from_now(months: i64) -> Result<NaiveDate, PeriodError> {
+    date_add_months(count(months, "months", "months_ago")?, "months")
+}
+
+/// Returns the date `years` years before today.
+///
+/// # Errors
+/// [`PeriodError::NegativeValue`] for a negative count (suggesting
+/// `years_from_now`), or [`PeriodError::OutOfRange`] on overflow.
+pub fn years_ago(years: i64) -> Result<NaiveDate, PeriodError> {
+    let n = months_in_years(count(years, "years", "years_from_now")?, "years")?;
+    date_sub_months(n, "years")
+}
+
+/// Returns the date `years` years after today.
+///
+/// # Errors
+/// [`PeriodError::NegativeValue`] for a negative count (suggesting
+/// `years_ago`), or [`PeriodError::OutOfRange`] on overflow.
+pub fn years_from_now(years: i64) -> Result<NaiveDate, PeriodError> {
+    let n = months_in_years(count(years, "years", "years_ago")?, "years")?;
+    date_add_months(n, "years")
+}
+
+/// Returns the instant `months` calendar months before now.
+///
+/// # Errors
+/// [`PeriodError::NegativeValue`] for a negative count, or
+/// [`PeriodError::OutOfRange`] on overflow.
+pub fn months_ago_datetime(months: i64) -> Result<DateTime<Local>, PeriodError> {
+    datetime_sub_months(count(months, "months", "months_from_now")?, "months")
+}
+
+/// Returns the instant `months` calendar months after now.
+///
+/// # Errors
+/// [`PeriodError::NegativeValue`] for a negative count, or
+/// [`PeriodError::OutOfRange`] on overflow.
+pub fn months_from_now_datetime(months: i64) -> Result<DateTime<Local>, PeriodError> {
+    datetime_add_months(count(months, "months", "months_ago")?, "months")
+}
+
+/// Returns the instant `years` years before now.
+///
+/// # Errors
+/// [`PeriodError::NegativeValue`] for a negative count, or
+/// [`PeriodError::OutOfRange`] on overflow.
+pub fn years_ago_datetime(years: i64) -> Result<DateTime<Local>, PeriodError> {
+    let n = months_in_years(count(years, "years", "years_from_now")?, "years")?;
+    datetime_sub_months(n, "years")
+}
+
+/// Returns the instant `years` years after now.
+///
+/// # Errors
+/// [`PeriodError::NegativeValue`] for a negative count, or
+/// [`PeriodError::OutOfRange`] on overflow.
+pub fn years_from_now_datetime(years: i64) -> Result<DateTime<Local>, PeriodError> {
+    let n = months_in_years(count(years, "years", "years_ago")?, "years")?;
+    datetime_add_months(n, "years")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_ago_returns_correct_date() {
+        let date = days_ago(3).unwrap();
+        let expected = Local::now().date_naive() - Duration::days(3);
+        assert_eq!(date, expected);
+    }
+
+    #[test]
+    fn test_days_ago_with_zero_days() {
+        let date = days_ago(0).unwrap();
+        let expected = Local::now().date_naive();
+        assert_eq!(date, expected);
+    }
+
+    #[test]
+    fn test_days_from_now_returns_correct_date() {
+        let date = days_from_now(3).unwrap();
+        let expected = Local::now().date_naive() + Duration::days(3);
+        assert_eq!(date, expected);
+    }
+
+    #[test]
+    fn test_weeks_ago_returns_correct_date() {
+        let date = weeks_ago(2).unwrap();
+        let expected = Local::now().date_naive() - Duration::weeks(2);
+        assert_eq!(date, expected);
+    }
+
+    #[test]
+    fn test_weeks_from_now_returns_correct_date() {
+        let date = weeks_from_now(2).unwrap();
+        let expected = Local::now().date_naive() + Duration::weeks(2);
+        assert_eq!(date, expected);
+    }
+
+    #[test]
+    fn test_yesterday_returns_previous_date() {
+        let date = yesterday().unwrap();
+        let expected = Local::now().date_naive() - Duration::days(1);
+        assert_eq!(date, expected);
+    }
+
+    #[test]
+    fn test_tomorrow_returns_next_date() {
+        let date = tomorrow().unwrap();
+        let expected = Local::now().date_naive() + Duration::days(1);
+        assert_eq!(date, expected);
+    }
+
+    #[test]
+    fn test_months_ago_returns_correct_date() {
+        let date = months_ago(2).unwrap();
+        let expected = Local::now().date_naive() - Months::new(2);
+        assert_eq!(date, expected);
+    }
+
+    #[test]
+    fn test_months_from_now_returns_correct_date() {
+        let date = months_from_now(2).unwrap();
+        let expected = Local::now().date_naive() + Months::new(2);
+        assert_eq!(date, expected);
+    }
+
+    #[test]
+    fn test_months_from_now_negative_suggests_months_ago() {
+        assert_eq!(
+            months_from_now(-3),
+            Err(PeriodError::NegativeValue {
+                unit: "months".to_string(),
+                suggestion: "months_ago".to_string(),
+                value: -3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_years_ago_negative_suggests_years_from_now() {
+        assert_eq!(
+            years_ago(-1),
+            Err(PeriodError::NegativeValue {
+                unit: "years".to_string(),
+                suggestion: "years_from_now".to_string(),
+                value: -1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_years_from_now_returns_correct_date() {
+        let date = years_from_now(1).unwrap();
+        let expected = Local::now().date_naive() + Months::new(12);
+        assert_eq!(date, expected);
+    }
+}
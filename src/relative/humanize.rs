@@ -1,5 +1,7 @@
 use chrono::{DateTime, Local};
 
+use crate::locale::{self, Locale, LocaleStrings};
+
 /// Returns a human-readable relative-time string for `datetime`.
 ///
 /// Past datetimes produce strings like `"3 minutes ago"` or `"yesterday"`.
@@ -34,91 +36,317 @@ use chrono::{DateTime, Local};
 #[inline]
 #[must_use]
 pub fn humanize(datetime: DateTime<Local>) -> String {
-    humanize_impl(datetime, Local::now())
+    humanize_with(datetime, &HumanizeConfig::default())
+}
+
+/// The coarsest unit [`humanize_with`] is allowed to render.
+///
+/// Capping the scale collapses everything above the cap into numeric counts of
+/// the cap unit — e.g. [`HumanizeUnit::Day`] renders a year as `"400 days ago"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HumanizeUnit {
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+impl HumanizeUnit {
+    fn rank(self) -> u8 {
+        match self {
+            HumanizeUnit::Minute => 2,
+            HumanizeUnit::Hour => 3,
+            HumanizeUnit::Day => 4,
+            HumanizeUnit::Month => 5,
+            HumanizeUnit::Year => 6,
+        }
+    }
+}
+
+/// The bucket boundaries that drive [`humanize`], all in seconds.
+///
+/// [`Thresholds::default`] reproduces the opinionated scale baked into the
+/// original `humanize`. Override any field to widen or tighten the rounding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Thresholds {
+    pub just_now_below: i64,
+    pub minute_fuzzy_below: i64,
+    pub minute_max: i64,
+    pub hour_fuzzy_below: i64,
+    pub hour_max: i64,
+    pub day_fuzzy_below: i64,
+    pub day_max: i64,
+    pub month_fuzzy_below: i64,
+    pub month_max: i64,
+    pub year_fuzzy_below: i64,
+    /// Seconds treated as one month for bucketing and counting.
+    pub month_secs: i64,
+    /// Seconds treated as one year for bucketing and counting.
+    pub year_secs: i64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        const MINUTE: i64 = 60;
+        const HOUR: i64 = 3_600;
+        const DAY: i64 = 86_400;
+        const MONTH: i64 = 30 * DAY;
+        Thresholds {
+            just_now_below: 30,
+            minute_fuzzy_below: 90,
+            minute_max: 45 * MINUTE,
+            hour_fuzzy_below: 90 * MINUTE,
+            hour_max: 22 * HOUR,
+            day_fuzzy_below: 36 * HOUR,
+            day_max: 25 * DAY,
+            month_fuzzy_below: 45 * DAY,
+            month_max: 10 * MONTH,
+            year_fuzzy_below: 18 * MONTH,
+            month_secs: MONTH,
+            year_secs: 365 * DAY,
+        }
+    }
+}
+
+/// Builder controlling how [`humanize_with`] renders a relative time.
+///
+/// Turns the fixed table in `humanize`'s doc comment into data the caller owns:
+/// override the bucket boundaries, disable the fuzzy article forms so `n = 1`
+/// always renders as `"1 minute ago"`, cap the coarsest unit, pick a locale, or
+/// pin an explicit reference `now` for deterministic output.
+#[derive(Debug, Clone)]
+pub struct HumanizeConfig {
+    now: Option<DateTime<Local>>,
+    fuzzy: bool,
+    max_unit: HumanizeUnit,
+    locale: Locale,
+    thresholds: Thresholds,
+}
+
+impl Default for HumanizeConfig {
+    fn default() -> Self {
+        HumanizeConfig {
+            now: None,
+            fuzzy: true,
+            max_unit: HumanizeUnit::Year,
+            locale: Locale::English,
+            thresholds: Thresholds::default(),
+        }
+    }
+}
+
+impl HumanizeConfig {
+    /// Creates a config equivalent to the zero-arg [`humanize`] defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pins the reference instant, making output deterministic.
+    #[must_use]
+    pub fn now(mut self, now: DateTime<Local>) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Enables or disables the fuzzy article forms (`"a minute ago"` vs
+    /// `"1 minute ago"`). Disabling also renders `"yesterday"`/`"tomorrow"`
+    /// numerically.
+    #[must_use]
+    pub fn fuzzy(mut self, fuzzy: bool) -> Self {
+        self.fuzzy = fuzzy;
+        self
+    }
+
+    /// Caps the coarsest unit the scale may use.
+    #[must_use]
+    pub fn max_unit(mut self, unit: HumanizeUnit) -> Self {
+        self.max_unit = unit;
+        self
+    }
+
+    /// Renders tokens in `locale`.
+    #[must_use]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Replaces the bucket boundaries wholesale.
+    #[must_use]
+    pub fn thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+}
+
+/// Like [`humanize`], but driven by a [`HumanizeConfig`]. [`humanize`] is this
+/// called with [`HumanizeConfig::default`].
+#[must_use]
+pub fn humanize_with(datetime: DateTime<Local>, config: &HumanizeConfig) -> String {
+    let now = config.now.unwrap_or_else(Local::now);
+    render(
+        datetime,
+        now,
+        &locale::resolve(&config.locale),
+        &config.thresholds,
+        config.fuzzy,
+        config.max_unit,
+    )
 }
 
+/// Like [`humanize`], but renders its tokens in `locale`.
+///
+/// Only the rendered strings — unit words, the article forms, the `ago`/`in`
+/// affixes, `"yesterday"`/`"tomorrow"` — are locale-dependent; the bucketing
+/// thresholds are identical to [`humanize`]. English is [`humanize`]'s default;
+/// additional locales can be supplied via
+/// [`register_locale`](crate::register_locale).
+#[inline]
+#[must_use]
+pub fn humanize_localized(datetime: DateTime<Local>, locale: &Locale) -> String {
+    humanize_localized_impl(datetime, Local::now(), &locale::resolve(locale))
+}
+
+#[cfg(test)]
 fn humanize_impl(datetime: DateTime<Local>, now: DateTime<Local>) -> String {
-    const MINUTE: i64 = 60;
-    const HOUR: i64 = 3_600;
-    const DAY: i64 = 86_400;
-    const MONTH: i64 = 30 * DAY;
-    const YEAR: i64 = 365 * DAY;
+    humanize_localized_impl(datetime, now, &locale::english())
+}
+
+fn humanize_localized_impl(
+    datetime: DateTime<Local>,
+    now: DateTime<Local>,
+    loc: &LocaleStrings,
+) -> String {
+    render(
+        datetime,
+        now,
+        loc,
+        &Thresholds::default(),
+        true,
+        HumanizeUnit::Year,
+    )
+}
+
+/// Which rendering a bucket naturally calls for, before the fuzzy/cap rules.
+enum Kind {
+    Fuzzy,
+    DayWord,
+    Num(i64),
+}
+
+fn unit_secs(rank: u8, t: &Thresholds) -> i64 {
+    match rank {
+        2 => 60,
+        3 => 3_600,
+        4 => 86_400,
+        5 => t.month_secs,
+        _ => t.year_secs,
+    }
+}
+
+fn unit_forms(rank: u8, loc: &LocaleStrings) -> &(String, String) {
+    match rank {
+        2 => &loc.minute,
+        3 => &loc.hour,
+        4 => &loc.day,
+        5 => &loc.month,
+        _ => &loc.year,
+    }
+}
+
+fn unit_article(rank: u8, loc: &LocaleStrings) -> &str {
+    match rank {
+        2 => &loc.a_minute,
+        3 => &loc.an_hour,
+        5 => &loc.a_month,
+        _ => &loc.a_year,
+    }
+}
 
+/// The shared bucketing core behind every `humanize` entry point. The order of
+/// the buckets is identical across locales and configs; the boundaries come
+/// from `t`, and the `fuzzy` toggle and `cap` change which token a bucket
+/// resolves to.
+fn render(
+    datetime: DateTime<Local>,
+    now: DateTime<Local>,
+    loc: &LocaleStrings,
+    t: &Thresholds,
+    fuzzy: bool,
+    cap: HumanizeUnit,
+) -> String {
     let secs = now.signed_duration_since(datetime).num_seconds();
     let is_past = secs >= 0;
     let abs = secs.saturating_abs();
 
-    if abs < 30 {
-        "just now".to_string()
-    } else if abs < 90 {
-        if is_past {
-            "a minute ago".to_string()
-        } else {
-            "in a minute".to_string()
-        }
-    } else if abs < 45 * MINUTE {
-        let n = abs / MINUTE;
-        let unit = if n == 1 { "minute" } else { "minutes" };
-        if is_past {
-            format!("{n} {unit} ago")
-        } else {
-            format!("in {n} {unit}")
-        }
-    } else if abs < 90 * MINUTE {
-        if is_past {
-            "an hour ago".to_string()
-        } else {
-            "in an hour".to_string()
-        }
-    } else if abs < 22 * HOUR {
-        let n = abs / HOUR;
-        let unit = if n == 1 { "hour" } else { "hours" };
-        if is_past {
-            format!("{n} {unit} ago")
-        } else {
-            format!("in {n} {unit}")
-        }
-    } else if abs < 36 * HOUR {
-        if is_past {
-            "yesterday".to_string()
-        } else {
-            "tomorrow".to_string()
-        }
-    } else if abs < 25 * DAY {
-        let n = abs / DAY;
-        let unit = if n == 1 { "day" } else { "days" };
-        if is_past {
-            format!("{n} {unit} ago")
-        } else {
-            format!("in {n} {unit}")
-        }
-    } else if abs < 45 * DAY {
-        if is_past {
-            "a month ago".to_string()
-        } else {
-            "in a month".to_string()
-        }
-    } else if abs < 10 * MONTH {
-        let n = abs / MONTH;
-        let unit = if n == 1 { "month" } else { "months" };
-        if is_past {
-            format!("{n} {unit} ago")
-        } else {
-            format!("in {n} {unit}")
+    // Wraps a core phrase (e.g. `"5 minutes"`) in the locale's past/future
+    // affix template.
+    let wrap = |core: &str| -> String {
+        let template = if is_past { &loc.past } else { &loc.future };
+        template.replace("{}", core)
+    };
+    let numeric = |rank: u8| {
+        let n = abs / unit_secs(rank, t);
+        wrap(&format!("{n} {}", LocaleStrings::plural(n, unit_forms(rank, loc))))
+    };
+    // The singular numeric form (`"1 minute"`), used when the fuzzy article is
+    // disabled — a fuzzy bucket always stands in for exactly one unit.
+    let one = |rank: u8| wrap(&format!("1 {}", unit_forms(rank, loc).0));
+
+    if abs < t.just_now_below {
+        return loc.just_now.clone();
+    }
+
+    let (rank, kind) = if abs < t.minute_fuzzy_below {
+        (2, Kind::Fuzzy)
+    } else if abs < t.minute_max {
+        (2, Kind::Num(abs / 60))
+    } else if abs < t.hour_fuzzy_below {
+        (3, Kind::Fuzzy)
+    } else if abs < t.hour_max {
+        (3, Kind::Num(abs / 3_600))
+    } else if abs < t.day_fuzzy_below {
+        (4, Kind::DayWord)
+    } else if abs < t.day_max {
+        (4, Kind::Num(abs / 86_400))
+    } else if abs < t.month_fuzzy_below {
+        (5, Kind::Fuzzy)
+    } else if abs < t.month_max {
+        (5, Kind::Num(abs / t.month_secs))
+    } else if abs < t.year_fuzzy_below {
+        (6, Kind::Fuzzy)
+    } else {
+        (6, Kind::Num(abs / t.year_secs))
+    };
+
+    // A unit coarser than the cap collapses into a numeric count of the cap.
+    if rank > cap.rank() {
+        return numeric(cap.rank());
+    }
+
+    match kind {
+        Kind::Num(n) => {
+            wrap(&format!("{n} {}", LocaleStrings::plural(n, unit_forms(rank, loc))))
         }
-    } else if abs < 18 * MONTH {
-        if is_past {
-            "a year ago".to_string()
-        } else {
-            "in a year".to_string()
+        Kind::Fuzzy => {
+            if fuzzy {
+                wrap(unit_article(rank, loc))
+            } else {
+                one(rank)
+            }
         }
-    } else {
-        let n = abs / YEAR;
-        let unit = if n == 1 { "year" } else { "years" };
-        if is_past {
-            format!("{n} {unit} ago")
-        } else {
-            format!("in {n} {unit}")
+        Kind::DayWord => {
+            if fuzzy {
+                if is_past {
+                    loc.yesterday.clone()
+                } else {
+                    loc.tomorrow.clone()
+                }
+            } else {
+                one(rank)
+            }
         }
     }
 }
@@ -143,6 +371,81 @@ mod tests {
         humanize_impl(now + Duration::seconds(secs), now)
     }
 
+    fn h_past_locale(secs: i64, locale: &Locale) -> String {
+        let now = Local::now();
+        humanize_localized_impl(now - Duration::seconds(secs), now, &locale::resolve(locale))
+    }
+
+    #[test]
+    fn test_humanize_localized_spanish_minutes_ago() {
+        assert_eq!(h_past_locale(5 * 60, &Locale::Spanish), "hace 5 minutos");
+    }
+
+    #[test]
+    fn test_humanize_localized_spanish_a_minute_ago() {
+        assert_eq!(h_past_locale(60, &Locale::Spanish), "hace un minuto");
+    }
+
+    #[test]
+    fn test_humanize_with_fuzzy_disabled_uses_numeric() {
+        let now = Local::now();
+        let dt = now - Duration::seconds(60);
+        let config = HumanizeConfig::new().now(now).fuzzy(false);
+        assert_eq!(humanize_with(dt, &config), "1 minute ago");
+    }
+
+    #[test]
+    fn test_humanize_with_fuzzy_disabled_month_bucket() {
+        // 30 days -> normally "a month ago", numeric form is "1 month ago".
+        let now = Local::now();
+        let dt = now - Duration::seconds(30 * 86_400);
+        let config = HumanizeConfig::new().now(now).fuzzy(false);
+        assert_eq!(humanize_with(dt, &config), "1 month ago");
+    }
+
+    #[test]
+    fn test_humanize_with_capped_at_days() {
+        let now = Local::now();
+        let dt = now - Duration::seconds(3 * 365 * 86_400);
+        let config = HumanizeConfig::new().now(now).max_unit(HumanizeUnit::Day);
+        assert_eq!(humanize_with(dt, &config), "1095 days ago");
+    }
+
+    #[test]
+    fn test_humanize_with_explicit_now_is_deterministic() {
+        let now = Local::now();
+        let dt = now - Duration::seconds(5 * 60);
+        let config = HumanizeConfig::new().now(now);
+        assert_eq!(humanize_with(dt, &config), "5 minutes ago");
+    }
+
+    #[test]
+    fn test_humanize_with_custom_thresholds() {
+        let now = Local::now();
+        let dt = now - Duration::seconds(120);
+        let thresholds = Thresholds { just_now_below: 600, ..Thresholds::default() };
+        let config = HumanizeConfig::new().now(now).thresholds(thresholds);
+        assert_eq!(humanize_with(dt, &config), "just now");
+    }
+
+    #[test]
+    fn test_humanize_with_locale_config() {
+        let now = Local::now();
+        let dt = now - Duration::seconds(5 * 60);
+        let config = HumanizeConfig::new().now(now).locale(Locale::Spanish);
+        assert_eq!(humanize_with(dt, &config), "hace 5 minutos");
+    }
+
+    #[test]
+    fn test_humanize_localized_english_matches_default() {
+        let now = Local::now();
+        let dt = now - Duration::seconds(5 * 60);
+        assert_eq!(
+            humanize_localized(dt, &Locale::English),
+            humanize_localized_impl(dt, now, &locale::english())
+        );
+    }
+
     #[test]
     fn test_humanize_just_now_past() {
         assert_eq!(h_past(10), "just now");
@@ -0,0 +1,238 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime};
+
+use crate::error::PeriodError;
+
+/// A half-open interval `[start, end)` resolved from a fuzzy period phrase.
+///
+/// Where the relative helpers and [`parse`](crate::parse) produce single
+/// instants, a `Span` captures the whole stretch a coarse word like
+/// `"this week"` or `"May 1969"` refers to. This lets callers ask whether a
+/// timestamp falls inside the phrase the user typed — handy for log and
+/// filtering front-ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+impl Span {
+    /// Returns `true` when `at` falls inside the half-open interval.
+    #[must_use]
+    pub fn contains(&self, at: NaiveDateTime) -> bool {
+        at >= self.start && at < self.end
+    }
+}
+
+/// Resolves a coarse period phrase into a half-open [`Span`], the way
+/// two-timer returns a `(start, end)` pair.
+///
+/// Supported phrases: `"today"`, `"yesterday"`, `"this week"`, `"last week"`,
+/// `"this weekend"`/`"last weekend"` (Saturday 00:00 through the following
+/// Monday 00:00), `"this month"`, `"<month> <year>"` such as `"May 1969"`,
+/// and bare months (resolved in `now`'s year) or bare four-digit years.
+///
+/// Weeks start on Monday (ISO); `now`'s weekday locates the enclosing Monday.
+/// Month and year spans run first-of-unit to first-of-next-unit via
+/// [`chrono::Months`]. `now` is injected so callers get deterministic,
+/// testable output just like `humanize_impl`.
+///
+/// # Errors
+///
+/// Returns [`PeriodError::ParseError`] for unrecognised input and
+/// [`PeriodError::AmbiguousDate`] when the resolved span would step off the
+/// representable `NaiveDate` range.
+pub fn span(input: &str, now: NaiveDateTime) -> Result<Span, PeriodError> {
+    let lower = input.trim().to_lowercase();
+    let tokens: Vec<&str> = lower.split_whitespace().collect();
+    let today = now.date();
+    let parse_err = || PeriodError::ParseError { input: input.to_string() };
+
+    match tokens.as_slice() {
+        ["today"] => Ok(day_span(today, 0)),
+        ["yesterday"] => Ok(day_span(today, -1)),
+        ["this", "week"] => Ok(week_span(today, 0)),
+        ["last", "week"] => Ok(week_span(today, -1)),
+        ["this", "weekend"] => Ok(weekend_span(today, 0)),
+        ["last", "weekend"] => Ok(weekend_span(today, -1)),
+        ["this", "month"] => month_span(input, today.year(), today.month()),
+        [month, year] => {
+            let m = month_from_word(month).ok_or_else(parse_err)?;
+            let y = year.parse::<i32>().map_err(|_| parse_err())?;
+            month_span(input, y, m)
+        }
+        [word] => {
+            if let Some(m) = month_from_word(word) {
+                month_span(input, today.year(), m)
+            } else if let Ok(y) = word.parse::<i32>() {
+                year_span(input, y)
+            } else {
+                Err(parse_err())
+            }
+        }
+        _ => Err(parse_err()),
+    }
+}
+
+/// Builds a one-day span starting `offset` days from `date`.
+fn day_span(date: NaiveDate, offset: i64) -> Span {
+    let start = date + Duration::days(offset);
+    Span { start: midnight(start), end: midnight(start + Duration::days(1)) }
+}
+
+/// Builds a week span (Monday 00:00 to the following Monday 00:00),
+/// shifted by `weeks` whole weeks.
+fn week_span(date: NaiveDate, weeks: i64) -> Span {
+    let monday = date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+        + Duration::weeks(weeks);
+    Span { start: midnight(monday), end: midnight(monday + Duration::days(7)) }
+}
+
+/// Builds a weekend span (Saturday 00:00 to Monday 00:00) for the week
+/// containing `date`, shifted by `weeks` whole weeks.
+fn weekend_span(date: NaiveDate, weeks: i64) -> Span {
+    let monday = date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+        + Duration::weeks(weeks);
+    let saturday = monday + Duration::days(5);
+    Span { start: midnight(saturday), end: midnight(saturday + Duration::days(2)) }
+}
+
+/// Builds a whole-month span from the first of `(year, month)` to the first
+/// of the following month.
+fn month_span(input: &str, year: i32, month: u32) -> Result<Span, PeriodError> {
+    let ambiguous = || PeriodError::AmbiguousDate { input: input.to_string() };
+    let start = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(ambiguous)?;
+    let end = start.checked_add_months(Months::new(1)).ok_or_else(ambiguous)?;
+    Ok(Span { start: midnight(start), end: midnight(end) })
+}
+
+/// Builds a whole-year span from January 1st to the following January 1st.
+fn year_span(input: &str, year: i32) -> Result<Span, PeriodError> {
+    let ambiguous = || PeriodError::AmbiguousDate { input: input.to_string() };
+    let start = NaiveDate::from_ymd_opt(year, 1, 1).ok_or_else(ambiguous)?;
+    let end = NaiveDate::from_ymd_opt(year + 1, 1, 1).ok_or_else(ambiguous)?;
+    Ok(Span { start: midnight(start), end: midnight(end) })
+}
+
+fn midnight(date: NaiveDate) -> NaiveDateTime {
+    date.and_hms_opt(0, 0, 0).expect("midnight is a valid time")
+}
+
+fn month_from_word(word: &str) -> Option<u32> {
+    match word {
+        "january" | "jan" => Some(1),
+        "february" | "feb" => Some(2),
+        "march" | "mar" => Some(3),
+        "april" | "apr" => Some(4),
+        "may" => Some(5),
+        "june" | "jun" => Some(6),
+        "july" | "jul" => Some(7),
+        "august" | "aug" => Some(8),
+        "september" | "sep" | "sept" => Some(9),
+        "october" | "oct" => Some(10),
+        "november" | "nov" => Some(11),
+        "december" | "dec" => Some(12),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A Wednesday (2026-02-25) at 14:30, used as the injected `now`.
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 2, 25)
+            .unwrap()
+            .and_hms_opt(14, 30, 0)
+            .unwrap()
+    }
+
+    fn midnight_ymd(y: i32, m: u32, d: u32) -> NaiveDateTime {
+        midnight(NaiveDate::from_ymd_opt(y, m, d).unwrap())
+    }
+
+    #[test]
+    fn test_span_today() {
+        let s = span("today", now()).unwrap();
+        assert_eq!(s.start, midnight_ymd(2026, 2, 25));
+        assert_eq!(s.end, midnight_ymd(2026, 2, 26));
+    }
+
+    #[test]
+    fn test_span_yesterday() {
+        let s = span("yesterday", now()).unwrap();
+        assert_eq!(s.start, midnight_ymd(2026, 2, 24));
+        assert_eq!(s.end, midnight_ymd(2026, 2, 25));
+    }
+
+    #[test]
+    fn test_span_this_week_starts_monday() {
+        let s = span("this week", now()).unwrap();
+        assert_eq!(s.start, midnight_ymd(2026, 2, 23));
+        assert_eq!(s.end, midnight_ymd(2026, 3, 2));
+    }
+
+    #[test]
+    fn test_span_last_week() {
+        let s = span("last week", now()).unwrap();
+        assert_eq!(s.start, midnight_ymd(2026, 2, 16));
+        assert_eq!(s.end, midnight_ymd(2026, 2, 23));
+    }
+
+    #[test]
+    fn test_span_this_weekend() {
+        let s = span("this weekend", now()).unwrap();
+        assert_eq!(s.start, midnight_ymd(2026, 2, 28));
+        assert_eq!(s.end, midnight_ymd(2026, 3, 2));
+    }
+
+    #[test]
+    fn test_span_last_weekend() {
+        let s = span("last weekend", now()).unwrap();
+        assert_eq!(s.start, midnight_ymd(2026, 2, 21));
+        assert_eq!(s.end, midnight_ymd(2026, 2, 23));
+    }
+
+    #[test]
+    fn test_span_this_month() {
+        let s = span("this month", now()).unwrap();
+        assert_eq!(s.start, midnight_ymd(2026, 2, 1));
+        assert_eq!(s.end, midnight_ymd(2026, 3, 1));
+    }
+
+    #[test]
+    fn test_span_named_month_and_year() {
+        let s = span("May 1969", now()).unwrap();
+        assert_eq!(s.start, midnight_ymd(1969, 5, 1));
+        assert_eq!(s.end, midnight_ymd(1969, 6, 1));
+    }
+
+    #[test]
+    fn test_span_bare_month_uses_now_year() {
+        let s = span("december", now()).unwrap();
+        assert_eq!(s.start, midnight_ymd(2026, 12, 1));
+        assert_eq!(s.end, midnight_ymd(2027, 1, 1));
+    }
+
+    #[test]
+    fn test_span_bare_year() {
+        let s = span("1969", now()).unwrap();
+        assert_eq!(s.start, midnight_ymd(1969, 1, 1));
+        assert_eq!(s.end, midnight_ymd(1970, 1, 1));
+    }
+
+    #[test]
+    fn test_span_contains() {
+        let s = span("May 1969", now()).unwrap();
+        assert!(s.contains(midnight_ymd(1969, 5, 20)));
+        assert!(!s.contains(midnight_ymd(1969, 6, 1)));
+    }
+
+    #[test]
+    fn test_span_unknown_is_error() {
+        assert_eq!(
+            span("whenever", now()),
+            Err(PeriodError::ParseError { input: "whenever".to_string() })
+        );
+    }
+}